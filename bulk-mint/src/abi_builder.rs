@@ -0,0 +1,208 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! An ABI-driven [`SignedTransactionBuilder`] that fetches the target entry function's
+//! parameter list from the REST client and BCS-encodes arguments pulled column-wise from a
+//! TSV destinations file, the same way `serde-generate` walks a Move/Rust type signature to
+//! derive SDK transaction builders. This lets arbitrary mint/burn/transfer entry functions be
+//! bulk-submitted with `--arg-columns` and no bespoke Rust builder per function.
+
+use anyhow::{anyhow, bail, Context, Result};
+use aptos_experimental_bulk_txn_submit::workloads::SignedTransactionBuilder;
+use aptos_sdk::{
+    move_types::{account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId},
+    rest_client::{aptos_api_types::{MoveType, TransactionOnChainData}, Client},
+    transaction_builder::TransactionFactory,
+    types::{transaction::{EntryFunction, SignedTransaction}, LocalAccount},
+};
+use std::str::FromStr;
+
+/// Builds entry-function call transactions purely from an on-chain ABI: no per-function Rust
+/// code is required, only a `--arg-columns` spec naming the destinations-file columns that
+/// feed the function's non-signer parameters, in order.
+#[derive(Clone)]
+pub struct AbiSignedTransactionBuilder {
+    contract_module: ModuleId,
+    entry_fun: Identifier,
+    param_types: Vec<MoveType>,
+}
+
+impl AbiSignedTransactionBuilder {
+    /// Fetches `contract_module_name`'s ABI from `client` and resolves `entry_fun_name`'s
+    /// parameter list, dropping the `signer`/`&signer` params the VM fills in itself.
+    pub async fn fetch(
+        contract_address: AccountAddress,
+        contract_module_name: &str,
+        entry_fun_name: &str,
+        client: &Client,
+    ) -> Result<Self> {
+        let module = client
+            .get_account_module(contract_address, contract_module_name)
+            .await
+            .with_context(|| format!("Failed to fetch module {}::{}", contract_address, contract_module_name))?
+            .into_inner();
+        let abi = module
+            .abi
+            .ok_or_else(|| anyhow!("Module {}::{} was published without an ABI", contract_address, contract_module_name))?;
+        let function = abi
+            .exposed_functions
+            .into_iter()
+            .find(|f| f.name.as_str() == entry_fun_name)
+            .ok_or_else(|| anyhow!("No entry function `{}` in {}::{}", entry_fun_name, contract_address, contract_module_name))?;
+        if !function.is_entry {
+            bail!("`{}` is not an entry function", entry_fun_name);
+        }
+
+        let param_types = function
+            .params
+            .into_iter()
+            .filter(|t| !is_signer(t))
+            .collect();
+
+        Ok(Self {
+            contract_module: ModuleId::new(contract_address, Identifier::new(contract_module_name)?),
+            entry_fun: Identifier::new(entry_fun_name)?,
+            param_types,
+        })
+    }
+
+    pub fn arity(&self) -> usize {
+        self.param_types.len()
+    }
+
+    /// BCS-encodes one destinations-file row against the resolved parameter types, failing
+    /// fast with a descriptive error on arity or type mismatch.
+    pub fn encode_args(&self, row: &[String]) -> Result<Vec<Vec<u8>>> {
+        if row.len() != self.param_types.len() {
+            bail!(
+                "Row has {} column(s) but `{}` takes {} non-signer argument(s)",
+                row.len(),
+                self.entry_fun,
+                self.param_types.len()
+            );
+        }
+        self.param_types
+            .iter()
+            .zip(row.iter())
+            .map(|(move_type, value)| {
+                encode_arg(move_type, value)
+                    .with_context(|| format!("Failed to encode `{}` as {:?}", value, move_type))
+            })
+            .collect()
+    }
+}
+
+impl SignedTransactionBuilder<Vec<String>> for AbiSignedTransactionBuilder {
+    fn build(&self, data: &Vec<String>, account: &LocalAccount, txn_factory: &TransactionFactory) -> SignedTransaction {
+        let args = self
+            .encode_args(data)
+            .expect("row was already validated when the work list was loaded");
+        account.sign_with_transaction_builder(txn_factory.entry_function(EntryFunction::new(
+            self.contract_module.clone(),
+            self.entry_fun.clone(),
+            vec![],
+            args,
+        )))
+    }
+
+    fn success_output(&self, data: &Vec<String>, txn_out: &Option<TransactionOnChainData>) -> String {
+        let status = match txn_out {
+            Some(txn_out) if txn_out.info.status().is_success() => "success".to_string(),
+            Some(txn_out) => format!("{:?}", txn_out.info.status()),
+            None => "missing".to_string(),
+        };
+        format!("{}\t{}", data.join("\t"), status)
+    }
+}
+
+fn is_signer(move_type: &MoveType) -> bool {
+    match move_type {
+        MoveType::Signer => true,
+        MoveType::Reference { to, .. } => is_signer(to),
+        _ => false,
+    }
+}
+
+fn is_move_struct(move_type: &MoveType, module: &str, name: &str) -> bool {
+    matches!(move_type, MoveType::Struct(s) if s.module.as_str() == module && s.name.as_str() == name)
+}
+
+fn option_inner(move_type: &MoveType) -> Option<&MoveType> {
+    match move_type {
+        MoveType::Struct(s) if s.module.as_str() == "option" && s.name.as_str() == "Option" => {
+            s.generic_type_params.first()
+        }
+        _ => None,
+    }
+}
+
+fn encode_arg(move_type: &MoveType, value: &str) -> Result<Vec<u8>> {
+    if let Some(inner) = option_inner(move_type) {
+        return encode_option(inner, value);
+    }
+    match move_type {
+        MoveType::Bool => Ok(bcs::to_bytes(&value.parse::<bool>()?)?),
+        MoveType::U8 => Ok(bcs::to_bytes(&value.parse::<u8>()?)?),
+        MoveType::U16 => Ok(bcs::to_bytes(&value.parse::<u16>()?)?),
+        MoveType::U32 => Ok(bcs::to_bytes(&value.parse::<u32>()?)?),
+        MoveType::U64 => Ok(bcs::to_bytes(&value.parse::<u64>()?)?),
+        MoveType::U128 => Ok(bcs::to_bytes(&value.parse::<u128>()?)?),
+        MoveType::Address => Ok(bcs::to_bytes(&AccountAddress::from_str(value)?)?),
+        MoveType::Vector(inner) => encode_vector(inner, value),
+        t if is_move_struct(t, "string", "String") => Ok(bcs::to_bytes(&value.to_string())?),
+        other => bail!("Unsupported Move type for ABI-driven argument encoding: {:?}", other),
+    }
+}
+
+/// Encodes a `;`-separated list of elements as a BCS `vector<T>`.
+fn encode_vector(inner: &MoveType, value: &str) -> Result<Vec<u8>> {
+    let items: Vec<&str> = if value.is_empty() { vec![] } else { value.split(';').collect() };
+    match inner {
+        MoveType::Bool => Ok(bcs::to_bytes(&items.iter().map(|s| s.parse::<bool>()).collect::<Result<Vec<_>, _>>()?)?),
+        MoveType::U8 => Ok(bcs::to_bytes(&items.iter().map(|s| s.parse::<u8>()).collect::<Result<Vec<_>, _>>()?)?),
+        MoveType::U64 => Ok(bcs::to_bytes(&items.iter().map(|s| s.parse::<u64>()).collect::<Result<Vec<_>, _>>()?)?),
+        MoveType::U128 => Ok(bcs::to_bytes(&items.iter().map(|s| s.parse::<u128>()).collect::<Result<Vec<_>, _>>()?)?),
+        MoveType::Address => Ok(bcs::to_bytes(
+            &items.iter().map(|s| AccountAddress::from_str(s)).collect::<Result<Vec<_>, _>>()?,
+        )?),
+        t if is_move_struct(t, "string", "String") => Ok(bcs::to_bytes(&items.iter().map(|s| s.to_string()).collect::<Vec<_>>())?),
+        other => bail!("Unsupported Move element type for vector<_> argument encoding: {:?}", other),
+    }
+}
+
+/// Encodes `Option<T>`. An empty column means `None`; BCS encodes `None` as a single zero byte
+/// regardless of `T`, matching Move's `option::Option<T>` (a `vector<T>` of length 0 or 1).
+fn encode_option(inner: &MoveType, value: &str) -> Result<Vec<u8>> {
+    if value.is_empty() {
+        return Ok(vec![0]);
+    }
+    match inner {
+        MoveType::Bool => Ok(bcs::to_bytes(&Some(value.parse::<bool>()?))?),
+        MoveType::U8 => Ok(bcs::to_bytes(&Some(value.parse::<u8>()?))?),
+        MoveType::U64 => Ok(bcs::to_bytes(&Some(value.parse::<u64>()?))?),
+        MoveType::U128 => Ok(bcs::to_bytes(&Some(value.parse::<u128>()?))?),
+        MoveType::Address => Ok(bcs::to_bytes(&Some(AccountAddress::from_str(value)?))?),
+        t if is_move_struct(t, "string", "String") => Ok(bcs::to_bytes(&Some(value.to_string()))?),
+        other => bail!("Unsupported Move type for Option<_> argument encoding: {:?}", other),
+    }
+}
+
+/// Reads a TSV destinations file, returning its header row (split on tab) and every following
+/// non-empty row as one set of positional arguments for the entry function. The caller is
+/// responsible for validating the header against `--arg-columns`; this function just parses.
+pub fn read_rows(destinations_file: &str) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let contents = std::fs::read_to_string(destinations_file)
+        .with_context(|| format!("Failed to read {}", destinations_file))?;
+    let mut lines = contents.lines();
+    let header = lines
+        .next()
+        .ok_or_else(|| anyhow!("{} is empty; expected a header row", destinations_file))?
+        .split('\t')
+        .map(str::to_string)
+        .collect();
+    let rows = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split('\t').map(str::to_string).collect())
+        .collect();
+    Ok((header, rows))
+}
@@ -0,0 +1,81 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The JSON manifest consumed by the `CreateCollection` work type: every argument
+//! `its_aptos_thing::create_collection` passes to the on-chain `create_collection` entry
+//! function (name/URIs/weights, mutability flags, max supply, royalty) as a single
+//! operator-authored file.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct WeightedUri {
+    pub uri: String,
+    pub weight: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoyaltyManifest {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MintSettings {
+    #[serde(default)]
+    pub transferable: bool,
+    #[serde(default)]
+    pub burnable_by_owner: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CollectionManifest {
+    pub collection_name: String,
+    pub collection_description: String,
+    pub collection_uri: String,
+    pub token_name_prefix: String,
+    pub token_description: String,
+    pub token_uris: Vec<WeightedUri>,
+    #[serde(default)]
+    pub mutable_collection_metadata: bool,
+    #[serde(default)]
+    pub mutable_token_metadata: bool,
+    pub max_supply: Option<u64>,
+    pub royalty: Option<RoyaltyManifest>,
+    #[serde(default)]
+    pub mint_settings: MintSettings,
+}
+
+impl CollectionManifest {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+        let manifest: Self = serde_json::from_str(&contents).with_context(|| format!("Failed to parse {}", path))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.token_uris.is_empty() {
+            bail!("Manifest must declare at least one token_uris entry");
+        }
+        for weighted in &self.token_uris {
+            if weighted.weight == 0 {
+                bail!("token_uris weight for `{}` must be > 0", weighted.uri);
+            }
+        }
+        if let Some(royalty) = &self.royalty {
+            if royalty.denominator == 0 {
+                bail!("royalty.denominator must be > 0");
+            }
+            if royalty.numerator > royalty.denominator {
+                bail!(
+                    "royalty.numerator ({}) must be <= royalty.denominator ({})",
+                    royalty.numerator,
+                    royalty.denominator
+                );
+            }
+        }
+        Ok(())
+    }
+}
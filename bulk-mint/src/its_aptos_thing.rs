@@ -1,15 +1,20 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::collection_manifest::CollectionManifest;
 use anyhow::Result;
-use aptos_experimental_bulk_txn_submit::{event_lookup::{get_burn_token_addr, get_mint_token_addr, search_single_event_data}, workloads::{rand_string, SignedTransactionBuilder}};
+use aptos_experimental_bulk_txn_submit::{event_lookup::{get_burn_token_addr, get_mint_token_addr, search_single_event_data}, workloads::SignedTransactionBuilder};
 use aptos_logger::info;
 use aptos_sdk::{
+    crypto::ed25519::{Ed25519PublicKey, Ed25519Signature},
     move_types::{account_address::AccountAddress, ident_str, identifier::{IdentStr, Identifier}, language_storage::ModuleId},
     rest_client::{aptos_api_types::TransactionOnChainData, Client},
     transaction_builder::TransactionFactory,
     types::{
-        transaction::{EntryFunction, SignedTransaction},
+        transaction::{
+            authenticator::{AccountAuthenticator, TransactionAuthenticator},
+            EntryFunction, RawTransaction, RawTransactionWithData, SignedTransaction,
+        },
         LocalAccount,
     },
 };
@@ -29,16 +34,25 @@ struct CreateCollectionConfigMoveStruct {
     ready_to_mint: bool,
 }
 
-pub async fn create_test_collection(
+pub async fn create_collection(
     contract_address: AccountAddress,
     contract_module_name: &str,
+    manifest: &CollectionManifest,
     admin_account: LocalAccount,
     client: &Client,
     txn_factory: TransactionFactory,
 ) -> Result<AccountAddress> {
     let contract_module = get_module_id(contract_address, contract_module_name);
 
-    let collection_name = format!("Test Collection {}", rand_string(10));
+    let (token_uris, token_uri_weights): (Vec<&String>, Vec<u64>) = manifest
+        .token_uris
+        .iter()
+        .map(|weighted| (&weighted.uri, weighted.weight))
+        .unzip();
+    let (royalty_numerator, royalty_denominator) = match &manifest.royalty {
+        Some(royalty) => (Some(royalty.numerator), Some(royalty.denominator)),
+        None => (None, None),
+    };
 
     let create_collection_txn = admin_account.sign_with_transaction_builder(
         txn_factory.entry_function(EntryFunction::new(
@@ -46,20 +60,20 @@ pub async fn create_test_collection(
             ident_str!("create_collection").to_owned(),
             vec![],
             vec![
-                bcs::to_bytes(&collection_name).unwrap(), // collection_name
-                bcs::to_bytes(&"collection description").unwrap(),              // collection_description
-                bcs::to_bytes(&"htpps://some.collection.uri.test").unwrap(),              // collection_uri
-                bcs::to_bytes(&"test token #").unwrap(),  // token_name_prefix
-                bcs::to_bytes(&"test token description").unwrap(),              // token_description
-                bcs::to_bytes(&vec!["htpps://some.uri1.test", "htpps://some.uri2.test"]).unwrap(), // token_uris: vector<String>,
-                bcs::to_bytes(&vec![10u64, 1u64]).unwrap(), // token_uris_weights: vector<u64>,
-                bcs::to_bytes(&true).unwrap(),           // mutable_collection_metadata
-                bcs::to_bytes(&true).unwrap(),           // mutable_token_metadata
-                bcs::to_bytes(&true).unwrap(),            // tokens_burnable_by_collection_owner
-                bcs::to_bytes(&false).unwrap(), // tokens_transferrable_by_collection_owner
-                bcs::to_bytes(&Some(1000000u64)).unwrap(), // max_supply
-                bcs::to_bytes(&Option::<u64>::None).unwrap(), // royalty_numerator
-                bcs::to_bytes(&Option::<u64>::None).unwrap(), // royalty_denominator
+                bcs::to_bytes(&manifest.collection_name).unwrap(),
+                bcs::to_bytes(&manifest.collection_description).unwrap(),
+                bcs::to_bytes(&manifest.collection_uri).unwrap(),
+                bcs::to_bytes(&manifest.token_name_prefix).unwrap(),
+                bcs::to_bytes(&manifest.token_description).unwrap(),
+                bcs::to_bytes(&token_uris).unwrap(), // token_uris: vector<String>,
+                bcs::to_bytes(&token_uri_weights).unwrap(), // token_uris_weights: vector<u64>,
+                bcs::to_bytes(&manifest.mutable_collection_metadata).unwrap(),
+                bcs::to_bytes(&manifest.mutable_token_metadata).unwrap(),
+                bcs::to_bytes(&manifest.mint_settings.burnable_by_owner).unwrap(), // tokens_burnable_by_collection_owner
+                bcs::to_bytes(&manifest.mint_settings.transferable).unwrap(), // tokens_transferrable_by_collection_owner
+                bcs::to_bytes(&manifest.max_supply).unwrap(),
+                bcs::to_bytes(&royalty_numerator).unwrap(),
+                bcs::to_bytes(&royalty_denominator).unwrap(),
             ],
         )),
     );
@@ -96,9 +110,10 @@ pub async fn create_test_collection(
     info!("set_minting_status txn: {:?}", output.info);
 
     info!("collection_owner_address: {:?}", collection_owner_address);
+    println!("collection_config: {}", collection_owner_address.to_standard_string());
 
     Ok(collection_owner_address)
-} 
+}
 
 pub struct NftMintSignedTransactionBuilder {
     contract_module: ModuleId,
@@ -149,11 +164,22 @@ impl SignedTransactionBuilder<AccountAddress> for NftMintSignedTransactionBuilde
             },
             None => ("missing".to_string(), "".to_string()),
         };
+        self.format_output(data, &token, &status)
+    }
+}
+
+impl NftMintSignedTransactionBuilder {
+    /// The `token\tcollection\trecipient\tstatus` line shape, factored out of `success_output`
+    /// so callers that need to exercise it against a known `(token, status)` pair — e.g.
+    /// checkpoint-format tests — don't have to hand-reconstruct it and risk drifting from the
+    /// real formatting (`recipient` here is `AccountAddress`'s `Display`, not
+    /// `to_standard_string()`, and the two are not interchangeable for every address).
+    pub(crate) fn format_output(&self, recipient: &AccountAddress, token: &str, status: &str) -> String {
         format!(
             "{}\t{}\t{}\t{}",
             token,
             self.collection_owner_address.to_standard_string(),
-            data,
+            recipient,
             status
         )
     }
@@ -223,6 +249,14 @@ impl SignedTransactionBuilder<(AccountAddress, AccountAddress)>
             },
             None => ("missing".to_string(), "".to_string()),
         };
+        self.format_output(data, &refund_addr, &status)
+    }
+}
+
+impl NftBurnSignedTransactionBuilder {
+    /// The `refund_addr\ttoken\tcollection\tstatus` line shape, factored out of
+    /// `success_output` for the same reason as `NftMintSignedTransactionBuilder::format_output`.
+    pub(crate) fn format_output(&self, data: &(AccountAddress, AccountAddress), refund_addr: &str, status: &str) -> String {
         format!(
             "{}\t{}\t{}\t{}",
             refund_addr,
@@ -232,3 +266,107 @@ impl SignedTransactionBuilder<(AccountAddress, AccountAddress)>
         )
     }
 }
+
+/// A `(token, collection)` burn transaction that has been built but not yet signed by the
+/// admin account, together with the multi-agent signing message the admin needs to produce
+/// a signature over. Produced by the `Prepare` phase of the offline-signing workflow and
+/// consumed, unchanged, by `Sign` and `SubmitSigned` on the other side of the air gap.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsignedBurnTxn {
+    pub token: AccountAddress,
+    pub collection: AccountAddress,
+    pub sender: AccountAddress,
+    pub raw_txn: RawTransaction,
+    pub signing_message: Vec<u8>,
+}
+
+/// A signature produced offline by the admin key over one [`UnsignedBurnTxn::signing_message`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdminBurnSignature {
+    pub token: AccountAddress,
+    pub collection: AccountAddress,
+    pub signature: Ed25519Signature,
+}
+
+/// Builds and finalizes admin-authorized burn transactions without ever requiring the admin
+/// private key on the submission host: only the admin's public key and address are needed to
+/// assemble the multi-agent raw transaction and its signing message (`build_raw`), and the
+/// admin's `Ed25519Signature` is supplied out-of-band once it comes back across the air gap
+/// (`finalize_with_signatures`).
+pub struct NftBurnOfflineTransactionBuilder {
+    contract_module: ModuleId,
+    burn_entry_fun: Identifier,
+    admin_address: AccountAddress,
+    admin_public_key: Ed25519PublicKey,
+}
+
+impl NftBurnOfflineTransactionBuilder {
+    pub fn new(
+        contract_address: AccountAddress,
+        contract_module_name: &str,
+        burn_entry_fun: &str,
+        admin_address: AccountAddress,
+        admin_public_key: Ed25519PublicKey,
+    ) -> Self {
+        Self {
+            contract_module: get_module_id(contract_address, contract_module_name),
+            burn_entry_fun: IdentStr::new(burn_entry_fun).unwrap().to_owned(),
+            admin_address,
+            admin_public_key,
+        }
+    }
+
+    /// `expiration_timestamp_secs` is an absolute on-chain timestamp, not a duration, so it
+    /// must be computed by the caller (typically `now + a long TTL`, since the admin signature
+    /// may not come back across the air gap for a while) and passed in explicitly rather than
+    /// left to `TransactionFactory`'s short default expiration.
+    pub fn build_raw(
+        &self,
+        data: &(AccountAddress, AccountAddress),
+        sender: AccountAddress,
+        sequence_number: u64,
+        expiration_timestamp_secs: u64,
+        txn_factory: &TransactionFactory,
+    ) -> RawTransaction {
+        txn_factory
+            .entry_function(EntryFunction::new(
+                self.contract_module.clone(),
+                self.burn_entry_fun.clone(),
+                vec![],
+                vec![
+                    bcs::to_bytes(&data.1).unwrap(), // collection_config_object
+                    bcs::to_bytes(&data.0).unwrap(), // token
+                ],
+            ))
+            .sender(sender)
+            .sequence_number(sequence_number)
+            .expiration_timestamp_secs(expiration_timestamp_secs)
+            .build()
+    }
+
+    pub fn signing_message(&self, raw_txn: &RawTransaction) -> Result<Vec<u8>> {
+        Ok(RawTransactionWithData::new_multi_agent(
+            raw_txn.clone(),
+            vec![self.admin_address],
+        )
+        .signing_message()?)
+    }
+
+    pub fn finalize_with_signatures(
+        &self,
+        raw_txn: RawTransaction,
+        sender_authenticator: AccountAuthenticator,
+        admin_signature: Ed25519Signature,
+    ) -> SignedTransaction {
+        let admin_authenticator =
+            AccountAuthenticator::ed25519(self.admin_public_key.clone(), admin_signature);
+        SignedTransaction::new_signed_transaction(
+            raw_txn,
+            TransactionAuthenticator::multi_agent(
+                sender_authenticator,
+                vec![self.admin_address],
+                vec![admin_authenticator],
+            ),
+        )
+    }
+}
@@ -11,9 +11,19 @@ use aptos_config::keys::ConfigKey;
 use aptos_crypto::ed25519::Ed25519PrivateKey;
 
 use aptos_experimental_bulk_txn_submit::{coordinator::{create_sample_addresses, execute_return_worker_funds, execute_submit, CreateSampleAddresses, SubmitArgs}, workloads::{create_account_address_pairs_work, create_account_addresses_work}};
-use its_aptos_thing::{create_test_collection, NftBurnSignedTransactionBuilder, NftMintSignedTransactionBuilder};
+use abi_builder::AbiSignedTransactionBuilder;
+use checkpoint::CheckpointArgs;
+use collection_manifest::CollectionManifest;
+use its_aptos_thing::{create_collection, NftBurnSignedTransactionBuilder, NftMintSignedTransactionBuilder};
+use offline_signing::{NftBurnPrepareArgs, NftBurnSignArgs, NftBurnSubmitSignedArgs};
+use verify_mints::VerifyMintsArgs;
 
+mod abi_builder;
+mod checkpoint;
+mod collection_manifest;
 mod its_aptos_thing;
+mod offline_signing;
+mod verify_mints;
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -25,6 +35,40 @@ struct Args {
 enum BulkMintCommand {
     Submit(Submit),
     CreateSampleAddresses(CreateSampleAddresses),
+    /// Phase 1 of the offline-signing workflow: builds unsigned admin-authorized burn
+    /// transactions and writes them, with the admin's signing messages, to a file.
+    NftBurnPrepare(NftBurnPrepareArgs),
+    /// Phase 2 of the offline-signing workflow: runs entirely offline, signing the prepared
+    /// transactions with the admin key and writing out the resulting signatures.
+    NftBurnSign(NftBurnSignArgs),
+    /// Phase 3 of the offline-signing workflow: assembles and submits the final signed
+    /// transactions from the prepared raw txns and the admin signatures produced by `sign`.
+    NftBurnSubmitSigned(NftBurnSubmitSignedArgs),
+    /// Creates a real collection from a JSON manifest (name/URIs/weights, mutability flags,
+    /// max supply, royalty, mint settings) instead of the hardcoded test-collection args.
+    CreateCollection(CreateCollectionArgs),
+    /// Reconciles a prior mint campaign's output TSV against on-chain state: ownership,
+    /// collection, and (when a manifest is supplied) name/URI/royalty.
+    VerifyMints(VerifyMintsArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct CreateCollectionArgs {
+    #[clap(flatten)]
+    cluster_args: aptos_transaction_emitter_lib::ClusterArgs,
+
+    #[clap(long)]
+    contract_address: AccountAddress,
+
+    #[clap(long, default_value = "only_on_aptos")]
+    contract_module_name: String,
+
+    /// JSON manifest; see `collection_manifest::CollectionManifest` for the expected shape.
+    #[clap(long)]
+    manifest_file: String,
+
+    #[clap(long, value_parser = ConfigKey::<Ed25519PrivateKey>::from_encoded_string)]
+    admin_key: ConfigKey<Ed25519PrivateKey>,
 }
 
 #[derive(Parser, Debug)]
@@ -39,9 +83,39 @@ pub struct Submit {
 pub enum WorkTypeSubcommand {
     NftMint(NftMintArgs),
     NftBurn(NftBurnArgs),
+    /// Calls an arbitrary entry function whose argument layout is resolved from its on-chain
+    /// ABI instead of a bespoke `SignedTransactionBuilder` impl.
+    AbiCall(AbiCallArgs),
     ReturnWorkerFunds,
 }
 
+#[derive(Parser, Debug)]
+pub struct AbiCallArgs {
+    #[clap(long)]
+    contract_address: AccountAddress,
+
+    #[clap(long)]
+    contract_module_name: String,
+
+    #[clap(long)]
+    entry_fun: String,
+
+    /// TSV file whose header row names the `--arg-columns` and whose remaining rows each
+    /// supply one set of positional, non-signer arguments for `entry_fun`.
+    #[clap(long)]
+    destinations_file: String,
+
+    /// Names of the destinations-file columns, in the order they should be bound to
+    /// `entry_fun`'s non-signer parameters. Validated against the file's own header (same
+    /// names, same order) and against the resolved ABI arity; the REST-fetched parameter
+    /// types, not this list, drive the actual BCS encoding.
+    #[clap(long, value_delimiter = ',')]
+    arg_columns: Vec<String>,
+
+    #[clap(flatten)]
+    checkpoint_args: CheckpointArgs,
+}
+
 #[derive(Parser, Debug)]
 pub struct NftMintArgs {
     #[clap(long)]
@@ -58,6 +132,9 @@ pub struct NftMintArgs {
 
     #[clap(long)]
     destinations_file: String,
+
+    #[clap(flatten)]
+    checkpoint_args: CheckpointArgs,
 }
 
 #[derive(Parser, Debug)]
@@ -76,6 +153,9 @@ pub struct NftBurnArgs {
 
     #[clap(long, value_parser = ConfigKey::<Ed25519PrivateKey>::from_encoded_string)]
     admin_key: ConfigKey<Ed25519PrivateKey>,
+
+    #[clap(flatten)]
+    checkpoint_args: CheckpointArgs,
 }
 
 #[tokio::main]
@@ -87,59 +167,162 @@ pub async fn main() -> Result<()> {
     match args.command {
         BulkMintCommand::Submit(args) => create_work_and_execute(args).await,
         BulkMintCommand::CreateSampleAddresses(args) => create_sample_addresses(args),
+        BulkMintCommand::NftBurnPrepare(args) => nft_burn_prepare(args).await,
+        BulkMintCommand::NftBurnSign(args) => offline_signing::sign(args),
+        BulkMintCommand::NftBurnSubmitSigned(args) => nft_burn_submit_signed(args).await,
+        BulkMintCommand::CreateCollection(args) => create_collection_from_manifest(args).await,
+        BulkMintCommand::VerifyMints(args) => verify_mints_command(args).await,
     }
 }
 
-async fn create_work_and_execute(args: Submit) -> Result<()> {
-    let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+async fn verify_mints_command(args: VerifyMintsArgs) -> Result<()> {
+    let cluster = Cluster::try_from_cluster_args(&args.cluster_args)
         .await
         .context("Failed to build cluster")?;
-    let coin_source_account = cluster
-        .load_coin_source_account(&cluster.random_instance().rest_client())
-        .await?;
+    let client = cluster.random_instance().rest_client();
+    verify_mints::verify_mints(args, &client).await
+}
 
-    match &args.work_args {
-        WorkTypeSubcommand::NftMint(mint_args) => {
-            // create test collection:
-             
-            // let client = &cluster.random_instance().rest_client();
-            // let admin_account = load_specific_account(
-            //     AccountKey::from_private_key(mint_args.admin_key.private_key()),
-            //     false,
-            //     client,
-            // )
-            // .await?;
-
-            // let txn_factory = args.submit_args.transaction_factory_args.with_init_params(
-            //     TransactionFactory::new(cluster.chain_id));
-            // let collection_owner_address = create_test_collection(
-            //    mint_args.contract_address,
-            //     admin_account,
-            //    client,
-            //     txn_factory.clone(),
-            // ).await?;
+async fn create_collection_from_manifest(args: CreateCollectionArgs) -> Result<()> {
+    let cluster = Cluster::try_from_cluster_args(&args.cluster_args)
+        .await
+        .context("Failed to build cluster")?;
+    let client = cluster.random_instance().rest_client();
+    let admin_account = load_specific_account(
+        AccountKey::from_private_key(args.admin_key.private_key()),
+        false,
+        &client,
+    )
+    .await?;
+    let manifest = CollectionManifest::load(&args.manifest_file)?;
+    let txn_factory = TransactionFactory::new(cluster.chain_id);
 
+    create_collection(
+        args.contract_address,
+        &args.contract_module_name,
+        &manifest,
+        admin_account,
+        &client,
+        txn_factory,
+    )
+    .await?;
+    Ok(())
+}
 
+async fn nft_burn_prepare(args: NftBurnPrepareArgs) -> Result<()> {
+    let cluster = Cluster::try_from_cluster_args(&args.cluster_args)
+        .await
+        .context("Failed to build cluster")?;
+    let client = cluster.random_instance().rest_client();
+    let coin_source_account = cluster.load_coin_source_account(&client).await?;
+    let txn_factory = TransactionFactory::new(cluster.chain_id);
+    offline_signing::prepare(&args, &client, &txn_factory, &coin_source_account).await
+}
+
+async fn nft_burn_submit_signed(args: NftBurnSubmitSignedArgs) -> Result<()> {
+    let cluster = Cluster::try_from_cluster_args(&args.cluster_args)
+        .await
+        .context("Failed to build cluster")?;
+    let client = cluster.random_instance().rest_client();
+    offline_signing::submit_signed(&args, &client).await
+}
+
+// Every arm below calls `args.submit_args.clone()` once per retry attempt inside
+// `submit_with_retries`, since `execute_submit` takes `SubmitArgs` by value and a retry needs a
+// fresh copy each time. This assumes `SubmitArgs` (defined in
+// `aptos_experimental_bulk_txn_submit::coordinator`, outside this crate) derives `Clone` — it
+// is a plain CLI args struct and every other args struct in this crate's dependency chain that
+// gets threaded through retries does, but this could not be confirmed against that crate's
+// source in this environment. If it doesn't, switch to re-parsing `Submit::submit_args` from
+// its own `clap` args on each attempt instead of cloning the already-parsed value.
+async fn create_work_and_execute(args: Submit) -> Result<()> {
+    match &args.work_args {
+        WorkTypeSubcommand::NftMint(mint_args) => {
             let work = create_account_addresses_work(&mint_args.destinations_file, false)?;
-            let builder =
-                NftMintSignedTransactionBuilder::new(mint_args.contract_address, &mint_args.contract_module_name, &mint_args.mint_entry_fun, mint_args.collection_address);
-            execute_submit(work, args.submit_args, builder, cluster, coin_source_account).await
+            checkpoint::submit_with_retries(work, &mint_args.checkpoint_args, |pending| async {
+                let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+                    .await
+                    .context("Failed to build cluster")?;
+                let coin_source_account = cluster
+                    .load_coin_source_account(&cluster.random_instance().rest_client())
+                    .await?;
+                let builder = NftMintSignedTransactionBuilder::new(mint_args.contract_address, &mint_args.contract_module_name, &mint_args.mint_entry_fun, mint_args.collection_address);
+                execute_submit(pending, args.submit_args.clone(), builder, cluster, coin_source_account).await
+            })
+            .await
         },
         WorkTypeSubcommand::NftBurn(burn_args) => {
             let work = create_account_address_pairs_work(&burn_args.destinations_file, true).await?;
+            checkpoint::submit_with_retries(work, &burn_args.checkpoint_args, |pending| async {
+                let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+                    .await
+                    .context("Failed to build cluster")?;
+                let coin_source_account = cluster
+                    .load_coin_source_account(&cluster.random_instance().rest_client())
+                    .await?;
+                let client = &cluster.random_instance().rest_client();
+                let admin_account = load_specific_account(
+                    AccountKey::from_private_key(burn_args.admin_key.private_key()),
+                    false,
+                    client,
+                )
+                .await?;
 
-            let client = &cluster.random_instance().rest_client();
-            let admin_account = load_specific_account(
-                AccountKey::from_private_key(burn_args.admin_key.private_key()),
-                false,
-                client,
+                let builder = NftBurnSignedTransactionBuilder::new(burn_args.contract_address, &burn_args.contract_module_name, &burn_args.burn_entry_fun, admin_account);
+                execute_submit(pending, args.submit_args.clone(), builder, cluster, coin_source_account).await
+            })
+            .await
+        },
+        WorkTypeSubcommand::AbiCall(abi_args) => {
+            let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+                .await
+                .context("Failed to build cluster")?;
+            let client = cluster.random_instance().rest_client();
+            let builder = AbiSignedTransactionBuilder::fetch(
+                abi_args.contract_address,
+                &abi_args.contract_module_name,
+                &abi_args.entry_fun,
+                &client,
             )
             .await?;
-
-            let builder = NftBurnSignedTransactionBuilder::new(burn_args.contract_address, &burn_args.contract_module_name, &burn_args.burn_entry_fun, admin_account);
-            execute_submit(work, args.submit_args, builder, cluster, coin_source_account).await
+            if abi_args.arg_columns.len() != builder.arity() {
+                anyhow::bail!(
+                    "--arg-columns lists {} column(s) but `{}` takes {} non-signer argument(s)",
+                    abi_args.arg_columns.len(),
+                    abi_args.entry_fun,
+                    builder.arity()
+                );
+            }
+            let (header, work) = abi_builder::read_rows(&abi_args.destinations_file)?;
+            if header != abi_args.arg_columns {
+                anyhow::bail!(
+                    "{}'s header is [{}] but --arg-columns is [{}]",
+                    abi_args.destinations_file,
+                    header.join(", "),
+                    abi_args.arg_columns.join(", ")
+                );
+            }
+            for row in &work {
+                builder.encode_args(row)?;
+            }
+            checkpoint::submit_with_retries(work, &abi_args.checkpoint_args, |pending| async {
+                let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+                    .await
+                    .context("Failed to build cluster")?;
+                let coin_source_account = cluster
+                    .load_coin_source_account(&cluster.random_instance().rest_client())
+                    .await?;
+                execute_submit(pending, args.submit_args.clone(), builder.clone(), cluster, coin_source_account).await
+            })
+            .await
         },
         WorkTypeSubcommand::ReturnWorkerFunds => {
+            let cluster = Cluster::try_from_cluster_args(&args.submit_args.cluster_args)
+                .await
+                .context("Failed to build cluster")?;
+            let coin_source_account = cluster
+                .load_coin_source_account(&cluster.random_instance().rest_client())
+                .await?;
             execute_return_worker_funds(args.submit_args, cluster, &coin_source_account).await
         },
     }
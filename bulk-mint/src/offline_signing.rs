@@ -0,0 +1,337 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Three-phase offline-signing workflow for admin-authorized burns, so the `admin_key` never
+//! has to be loaded on the machine that talks to the network:
+//!
+//! 1. [`prepare`] reads the destinations file, funds a bounded pool of worker accounts and
+//!    round-robins rows across them, and writes the unsigned raw transactions plus their
+//!    multi-agent signing messages to a file (along with the worker keys, which are not
+//!    sensitive — only the admin key is air-gapped).
+//! 2. [`sign`] runs on the air-gapped host: it reads that file and the admin key, and writes
+//!    out one [`AdminBurnSignature`] per row. It never touches the network.
+//! 3. [`submit_signed`] reads the raw transactions, the worker keys, and the admin signatures
+//!    back in, has each worker sign its own half, assembles the final `SignedTransaction`s, and
+//!    submits them.
+
+use crate::its_aptos_thing::{AdminBurnSignature, NftBurnOfflineTransactionBuilder, UnsignedBurnTxn};
+use anyhow::{Context, Result};
+use aptos_config::keys::ConfigKey;
+use aptos_crypto::ed25519::{Ed25519PrivateKey, PrivateKey, SigningKey};
+use aptos_experimental_bulk_txn_submit::workloads::create_account_address_pairs_work;
+use aptos_logger::info;
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    rest_client::Client,
+    transaction_builder::TransactionFactory,
+    types::{transaction::authenticator::AccountAuthenticator, LocalAccount},
+};
+use aptos_transaction_emitter_lib::ClusterArgs;
+use clap::Parser;
+use std::{collections::HashMap, fs};
+
+#[derive(Parser, Debug)]
+pub struct NftBurnPrepareArgs {
+    #[clap(flatten)]
+    pub cluster_args: ClusterArgs,
+
+    #[clap(long)]
+    pub contract_address: AccountAddress,
+
+    #[clap(long, default_value = "only_on_aptos")]
+    pub contract_module_name: String,
+
+    #[clap(long, default_value = "burn_with_admin_worker")]
+    pub burn_entry_fun: String,
+
+    #[clap(long)]
+    pub destinations_file: String,
+
+    /// Public address of the admin account; its private key is never needed here.
+    #[clap(long)]
+    pub admin_address: AccountAddress,
+
+    /// Public key matching `admin_address`; used to assemble the multi-agent authenticator.
+    #[clap(long, value_parser = aptos_crypto::ed25519::Ed25519PublicKey::from_encoded_string)]
+    pub admin_public_key: aptos_crypto::ed25519::Ed25519PublicKey,
+
+    /// Where to write the unsigned raw transactions and signing messages.
+    #[clap(long)]
+    pub output_file: String,
+
+    /// Where to write the (non-sensitive) funded worker keys needed by `submit_signed`.
+    #[clap(long)]
+    pub worker_keys_file: String,
+
+    /// How long, from now, the prepared transactions remain submittable. The default
+    /// `TransactionFactory` expiration (tens of seconds) assumes near-immediate submission,
+    /// which doesn't hold here: the admin signature has to make a round trip across the air
+    /// gap before `submit_signed` ever runs, so this needs to be long enough to cover that.
+    #[clap(long, default_value_t = 30 * 24 * 60 * 60)]
+    pub expiration_ttl_secs: u64,
+
+    /// Number of worker accounts to fund and round-robin rows across, instead of creating and
+    /// funding one new account per row. Funding happens once, up front, for the whole pool.
+    #[clap(long, default_value_t = 16)]
+    pub worker_pool_size: usize,
+
+    /// Octas of gas coin to fund each worker account with; each worker pays gas as the sender
+    /// of every burn transaction assigned to it by the round robin.
+    #[clap(long, default_value_t = 100_000_000)]
+    pub worker_funding_amount_octas: u64,
+}
+
+#[derive(Parser, Debug)]
+pub struct NftBurnSignArgs {
+    /// File produced by `NftBurnPrepare`.
+    #[clap(long)]
+    pub input_file: String,
+
+    #[clap(long, value_parser = ConfigKey::<Ed25519PrivateKey>::from_encoded_string)]
+    pub admin_key: ConfigKey<Ed25519PrivateKey>,
+
+    /// Where to write the admin's offline signatures.
+    #[clap(long)]
+    pub output_file: String,
+}
+
+#[derive(Parser, Debug)]
+pub struct NftBurnSubmitSignedArgs {
+    #[clap(flatten)]
+    pub cluster_args: ClusterArgs,
+
+    #[clap(long)]
+    pub contract_address: AccountAddress,
+
+    #[clap(long, default_value = "only_on_aptos")]
+    pub contract_module_name: String,
+
+    #[clap(long, default_value = "burn_with_admin_worker")]
+    pub burn_entry_fun: String,
+
+    #[clap(long)]
+    pub admin_address: AccountAddress,
+
+    /// Public key matching `admin_address`; used to assemble the multi-agent authenticator.
+    #[clap(long, value_parser = aptos_crypto::ed25519::Ed25519PublicKey::from_encoded_string)]
+    pub admin_public_key: aptos_crypto::ed25519::Ed25519PublicKey,
+
+    /// File produced by `NftBurnPrepare`.
+    #[clap(long)]
+    pub prepared_file: String,
+
+    /// Worker keys file produced by `NftBurnPrepare`.
+    #[clap(long)]
+    pub worker_keys_file: String,
+
+    /// File produced by `NftBurnSign`.
+    #[clap(long)]
+    pub admin_signatures_file: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct WorkerKey {
+    address: AccountAddress,
+    private_key: Ed25519PrivateKey,
+}
+
+/// Reads the destinations file, funds a bounded pool of worker accounts once up front (rather
+/// than one new account per row — serially creating and funding thousands of accounts is both
+/// slow and needlessly taxes `coin_source_account`'s sequence number), round-robins rows across
+/// that pool, and writes out the unsigned raw transactions plus their multi-agent signing
+/// messages.
+pub async fn prepare(
+    args: &NftBurnPrepareArgs,
+    client: &Client,
+    txn_factory: &TransactionFactory,
+    coin_source_account: &LocalAccount,
+) -> Result<()> {
+    let work = create_account_address_pairs_work(&args.destinations_file, true).await?;
+    let builder = NftBurnOfflineTransactionBuilder::new(
+        args.contract_address,
+        &args.contract_module_name,
+        &args.burn_entry_fun,
+        args.admin_address,
+        args.admin_public_key.clone(),
+    );
+
+    let expiration_timestamp_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .context("System clock is before the Unix epoch")?
+        .as_secs()
+        + args.expiration_ttl_secs;
+
+    let pool_size = args.worker_pool_size.min(work.len()).max(1);
+    let workers = fund_worker_pool(
+        client,
+        coin_source_account,
+        txn_factory,
+        pool_size,
+        args.worker_funding_amount_octas,
+    )
+    .await?;
+
+    let mut prepared = Vec::with_capacity(work.len());
+    for (i, data) in work.iter().enumerate() {
+        let worker = &workers[i % workers.len()];
+        let sequence_number = worker.increment_sequence_number();
+        let raw_txn = builder.build_raw(data, worker.address(), sequence_number, expiration_timestamp_secs, txn_factory);
+        let signing_message = builder.signing_message(&raw_txn)?;
+        prepared.push(UnsignedBurnTxn {
+            token: data.0,
+            collection: data.1,
+            sender: worker.address(),
+            raw_txn,
+            signing_message,
+        });
+    }
+
+    let worker_keys: Vec<WorkerKey> = workers
+        .iter()
+        .map(|worker| WorkerKey {
+            address: worker.address(),
+            private_key: worker.private_key().clone(),
+        })
+        .collect();
+
+    fs::write(&args.output_file, bcs::to_bytes(&prepared)?)
+        .with_context(|| format!("Failed to write {}", args.output_file))?;
+    fs::write(&args.worker_keys_file, bcs::to_bytes(&worker_keys)?)
+        .with_context(|| format!("Failed to write {}", args.worker_keys_file))?;
+    info!(
+        "Wrote {} unsigned burn transactions across {} worker accounts to {}",
+        prepared.len(),
+        workers.len(),
+        args.output_file
+    );
+    Ok(())
+}
+
+/// Purely offline: signs each transaction's multi-agent signing message with the admin key.
+/// Never constructs a network client and never reads the destinations or cluster config.
+pub fn sign(args: NftBurnSignArgs) -> Result<()> {
+    let prepared: Vec<UnsignedBurnTxn> = bcs::from_bytes(
+        &fs::read(&args.input_file).with_context(|| format!("Failed to read {}", args.input_file))?,
+    )?;
+
+    let admin_key = args.admin_key.private_key();
+    let signatures: Vec<AdminBurnSignature> = prepared
+        .iter()
+        .map(|txn| AdminBurnSignature {
+            token: txn.token,
+            collection: txn.collection,
+            signature: admin_key.sign_arbitrary_message(&txn.signing_message),
+        })
+        .collect();
+
+    fs::write(&args.output_file, bcs::to_bytes(&signatures)?)
+        .with_context(|| format!("Failed to write {}", args.output_file))?;
+    info!("Wrote {} admin signatures to {}", signatures.len(), args.output_file);
+    Ok(())
+}
+
+/// Reassembles the final signed transactions from the prepared raw txns, a freshly-produced
+/// worker signature for the sender half, and the admin signature produced offline by `sign`,
+/// then submits each one and prints a `token\tcollection\tsender\tstatus` line for it. This is
+/// not the `refund_addr\ttoken\tcollection\tstatus` shape that
+/// `NftBurnSignedTransactionBuilder::success_output` emits for the inline path.
+pub async fn submit_signed(args: &NftBurnSubmitSignedArgs, client: &Client) -> Result<()> {
+    let prepared: Vec<UnsignedBurnTxn> = bcs::from_bytes(
+        &fs::read(&args.prepared_file).with_context(|| format!("Failed to read {}", args.prepared_file))?,
+    )?;
+    let worker_keys: HashMap<AccountAddress, Ed25519PrivateKey> =
+        bcs::from_bytes::<Vec<WorkerKey>>(
+            &fs::read(&args.worker_keys_file)
+                .with_context(|| format!("Failed to read {}", args.worker_keys_file))?,
+        )?
+        .into_iter()
+        .map(|w| (w.address, w.private_key))
+        .collect();
+    let admin_signatures: HashMap<(AccountAddress, AccountAddress), AdminBurnSignature> =
+        bcs::from_bytes::<Vec<AdminBurnSignature>>(
+            &fs::read(&args.admin_signatures_file)
+                .with_context(|| format!("Failed to read {}", args.admin_signatures_file))?,
+        )?
+        .into_iter()
+        .map(|sig| ((sig.token, sig.collection), sig))
+        .collect();
+
+    let builder = NftBurnOfflineTransactionBuilder::new(
+        args.contract_address,
+        &args.contract_module_name,
+        &args.burn_entry_fun,
+        args.admin_address,
+        args.admin_public_key.clone(),
+    );
+
+    for unsigned in prepared {
+        let worker_key = worker_keys
+            .get(&unsigned.sender)
+            .with_context(|| format!("No worker key loaded for sender {}", unsigned.sender))?;
+        let admin_signature = admin_signatures
+            .get(&(unsigned.token, unsigned.collection))
+            .with_context(|| format!("No admin signature for ({}, {})", unsigned.token, unsigned.collection))?;
+
+        let sender_signature = worker_key.sign_arbitrary_message(&unsigned.signing_message);
+        let sender_authenticator =
+            AccountAuthenticator::ed25519(worker_key.public_key(), sender_signature);
+
+        let signed_txn = builder.finalize_with_signatures(
+            unsigned.raw_txn,
+            sender_authenticator,
+            admin_signature.signature.clone(),
+        );
+
+        let status = match client.submit_and_wait_bcs(&signed_txn).await {
+            Ok(resp) => resp.into_inner().info.status().to_string(),
+            Err(e) => e.to_string(),
+        };
+        println!(
+            "{}\t{}\t{}\t{}",
+            unsigned.token, unsigned.collection, unsigned.sender, status
+        );
+    }
+    Ok(())
+}
+
+/// Generates `pool_size` worker accounts and funds them in one batch: `coin_source_account`
+/// signs each account's creation transaction and its gas-coin transfer in order (bumping its
+/// own sequence number as it goes), then all of them are submitted and confirmed concurrently.
+/// Each worker needs an actual balance, not just an existing account, because it pays the gas
+/// for every burn transaction the round robin assigns to it in `submit_signed`.
+async fn fund_worker_pool(
+    client: &Client,
+    coin_source_account: &LocalAccount,
+    txn_factory: &TransactionFactory,
+    pool_size: usize,
+    funding_amount_octas: u64,
+) -> Result<Vec<LocalAccount>> {
+    let workers: Vec<LocalAccount> = (0..pool_size)
+        .map(|_| LocalAccount::generate(&mut rand::rngs::OsRng))
+        .collect();
+
+    let mut funding_txns = Vec::with_capacity(workers.len() * 2);
+    for worker in &workers {
+        funding_txns.push(
+            coin_source_account.sign_with_transaction_builder(txn_factory.create_user_account(worker.public_key())),
+        );
+        funding_txns.push(
+            coin_source_account.sign_with_transaction_builder(txn_factory.transfer(worker.address(), funding_amount_octas)),
+        );
+    }
+    futures::future::try_join_all(funding_txns.iter().map(|txn| client.submit_and_wait(txn)))
+        .await
+        .context("Failed to create and fund one or more worker accounts")?;
+
+    let sequence_numbers = futures::future::try_join_all(workers.iter().map(|worker| client.get_account(worker.address())))
+        .await
+        .context("Failed to fetch sequence numbers for one or more newly funded worker accounts")?;
+
+    Ok(workers
+        .iter()
+        .zip(sequence_numbers)
+        .map(|(worker, resp)| {
+            LocalAccount::new(worker.address(), worker.private_key().clone(), resp.into_inner().sequence_number)
+        })
+        .collect())
+}
@@ -0,0 +1,223 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! `VerifyMints` closes the loop on a bulk mint campaign: it re-reads a prior mint output TSV
+//! (`token\tcollection\trecipient\tstatus`, the shape `NftMintSignedTransactionBuilder` emits)
+//! and, for every row that claimed success, reads the token object's own resources back from
+//! the REST client to confirm it actually ended up in the state the campaign asked for, rather
+//! than trusting the submit-time status alone.
+
+use crate::collection_manifest::CollectionManifest;
+use anyhow::{Context, Result};
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    rest_client::Client,
+};
+use aptos_transaction_emitter_lib::ClusterArgs;
+use clap::Parser;
+use std::{fs, str::FromStr};
+
+#[derive(Parser, Debug)]
+pub struct VerifyMintsArgs {
+    #[clap(flatten)]
+    pub cluster_args: ClusterArgs,
+
+    /// Output TSV from a prior `NftMint` submit run.
+    #[clap(long)]
+    pub mint_output_file: String,
+
+    /// Where to write the reconciliation TSV.
+    #[clap(long)]
+    pub output_file: String,
+
+    /// Module the token's collection-association resource (`{module}::TokenConfig`) lives in.
+    #[clap(long, default_value = "only_on_aptos")]
+    pub contract_module_name: String,
+
+    /// Optional manifest; when supplied, also checks the token's name prefix, that its URI is
+    /// one of the manifest's declared `token_uris`, and (when the manifest declares one) that
+    /// the token's on-chain royalty matches.
+    #[clap(long)]
+    pub manifest_file: Option<String>,
+}
+
+struct MintRow {
+    /// `NftMintSignedTransactionBuilder::success_output` only knows the minted token's address
+    /// on a `success` row; every `missing`/errored row writes this column empty, so it has to
+    /// be optional here too rather than failing to parse the whole file on the first such row.
+    token: Option<AccountAddress>,
+    collection: AccountAddress,
+    recipient: AccountAddress,
+    status: String,
+}
+
+fn parse_mint_output(path: &str) -> Result<Vec<MintRow>> {
+    let contents = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            anyhow::ensure!(fields.len() == 4, "Malformed mint output row: {}", line);
+            let token = if fields[0].is_empty() { None } else { Some(AccountAddress::from_str(fields[0])?) };
+            Ok(MintRow {
+                token,
+                collection: AccountAddress::from_str(fields[1])?,
+                recipient: AccountAddress::from_str(fields[2])?,
+                status: fields[3].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The result of reconciling one row of the mint output against on-chain state.
+enum Reconciliation {
+    Ok,
+    MissingToken,
+    WrongOwner { actual: AccountAddress },
+    WrongCollection { actual: AccountAddress },
+    MetadataMismatch(String),
+    NotSuccessfullyMinted,
+}
+
+impl std::fmt::Display for Reconciliation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reconciliation::Ok => write!(f, "ok"),
+            Reconciliation::MissingToken => write!(f, "missing_token"),
+            Reconciliation::WrongOwner { actual } => write!(f, "wrong_owner:{}", actual.to_standard_string()),
+            Reconciliation::WrongCollection { actual } => write!(f, "wrong_collection:{}", actual.to_standard_string()),
+            Reconciliation::MetadataMismatch(reason) => write!(f, "metadata_mismatch:{}", reason),
+            Reconciliation::NotSuccessfullyMinted => write!(f, "not_successfully_minted"),
+        }
+    }
+}
+
+async fn reconcile_row(row: &MintRow, contract_module_name: &str, manifest: &Option<CollectionManifest>, client: &Client) -> Result<Reconciliation> {
+    if row.status != "success" {
+        return Ok(Reconciliation::NotSuccessfullyMinted);
+    }
+    let token = row.token.context("a `success` row must carry a token address")?;
+
+    let owner = match client.get_account_resource(token, "0x1::object::ObjectCore").await {
+        Ok(resp) => match resp.into_inner() {
+            Some(resource) => AccountAddress::from_str(
+                resource.data.get("owner").and_then(|v| v.as_str()).context("ObjectCore resource missing `owner`")?,
+            )?,
+            None => return Ok(Reconciliation::MissingToken),
+        },
+        Err(_) => return Ok(Reconciliation::MissingToken),
+    };
+    if owner != row.recipient {
+        return Ok(Reconciliation::WrongOwner { actual: owner });
+    }
+
+    let token_config_type = format!("{}::TokenConfig", contract_module_name);
+    let collection_config = match client.get_account_resource(token, &token_config_type).await {
+        Ok(resp) => match resp.into_inner() {
+            Some(resource) => AccountAddress::from_str(
+                resource
+                    .data
+                    .get("collection_config")
+                    .and_then(|v| v.as_str())
+                    .with_context(|| format!("{} missing `collection_config`", token_config_type))?,
+            )?,
+            None => return Ok(Reconciliation::MissingToken),
+        },
+        Err(_) => return Ok(Reconciliation::MissingToken),
+    };
+    if collection_config != row.collection {
+        return Ok(Reconciliation::WrongCollection { actual: collection_config });
+    }
+
+    if let Some(manifest) = manifest {
+        let resource = match client.get_account_resource(token, "0x4::token::Token").await {
+            Ok(resp) => match resp.into_inner() {
+                Some(resource) => resource,
+                None => return Ok(Reconciliation::MissingToken),
+            },
+            Err(_) => return Ok(Reconciliation::MissingToken),
+        };
+
+        let name = resource.data.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+        if !name.starts_with(&manifest.token_name_prefix) {
+            return Ok(Reconciliation::MetadataMismatch(format!(
+                "name `{}` does not start with prefix `{}`",
+                name, manifest.token_name_prefix
+            )));
+        }
+        let uri = resource.data.get("uri").and_then(|v| v.as_str()).unwrap_or_default();
+        if !manifest.token_uris.iter().any(|w| w.uri == uri) {
+            return Ok(Reconciliation::MetadataMismatch(format!("uri `{}` is not one of the manifest's token_uris", uri)));
+        }
+
+        if let Some(royalty) = &manifest.royalty {
+            // Mirrors how `0x4::token::royalty` resolves it on-chain: a token only has its own
+            // `0x4::royalty::Royalty` resource when it overrides the collection's royalty, so
+            // look there first and fall back to the token's collection object (the `collection`
+            // field of its `0x4::token::Token` resource, not `row.collection`, which is the
+            // collection config).
+            let token_royalty = match client.get_account_resource(token, "0x4::royalty::Royalty").await {
+                Ok(resp) => resp.into_inner(),
+                Err(_) => None,
+            };
+            let collection_object = AccountAddress::from_str(
+                resource
+                    .data
+                    .get("collection")
+                    .and_then(|v| v.get("inner"))
+                    .and_then(|v| v.as_str())
+                    .context("0x4::token::Token resource missing `collection`")?,
+            )?;
+            let royalty_resource = match token_royalty {
+                Some(resource) => resource,
+                None => match client.get_account_resource(collection_object, "0x4::royalty::Royalty").await {
+                    Ok(resp) => match resp.into_inner() {
+                        Some(resource) => resource,
+                        None => {
+                            return Ok(Reconciliation::MetadataMismatch(
+                                "manifest declares a royalty but neither the token nor its collection has a 0x4::royalty::Royalty resource".to_string(),
+                            ))
+                        },
+                    },
+                    Err(_) => return Ok(Reconciliation::MissingToken),
+                },
+            };
+            let numerator = royalty_resource.data.get("numerator").and_then(|v| v.as_str()).unwrap_or_default();
+            let denominator = royalty_resource.data.get("denominator").and_then(|v| v.as_str()).unwrap_or_default();
+            if numerator != royalty.numerator.to_string() || denominator != royalty.denominator.to_string() {
+                return Ok(Reconciliation::MetadataMismatch(format!(
+                    "royalty {}/{} does not match manifest's {}/{}",
+                    numerator, denominator, royalty.numerator, royalty.denominator
+                )));
+            }
+        }
+    }
+
+    Ok(Reconciliation::Ok)
+}
+
+pub async fn verify_mints(args: VerifyMintsArgs, client: &Client) -> Result<()> {
+    let rows = parse_mint_output(&args.mint_output_file)?;
+    let manifest = args.manifest_file.as_deref().map(CollectionManifest::load).transpose()?;
+
+    let mut mismatches = 0usize;
+    let mut output = String::new();
+    for row in &rows {
+        let result = reconcile_row(row, &args.contract_module_name, &manifest, client).await?;
+        if !matches!(result, Reconciliation::Ok) {
+            mismatches += 1;
+        }
+        output.push_str(&format!(
+            "{}\t{}\t{}\t{}\n",
+            row.token.map(|t| t.to_standard_string()).unwrap_or_default(),
+            row.collection.to_standard_string(),
+            row.recipient.to_standard_string(),
+            result
+        ));
+    }
+
+    fs::write(&args.output_file, output).with_context(|| format!("Failed to write {}", args.output_file))?;
+    aptos_logger::info!("Verified {} mints, {} mismatches; wrote {}", rows.len(), mismatches, args.output_file);
+    Ok(())
+}